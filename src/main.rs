@@ -1,11 +1,235 @@
 #![windows_subsystem = "windows"]
 
+mod report;
+
+use report::{
+    parse_output_format, CpuInfo, DiskEntry, LanguageToolchain, LocaleInfo, MemoryInfo,
+    NetworkInfo, NetworkInterfaceEntry, OsInfo, ProcessEntry, SystemReport,
+};
 use std::env;
+use std::ffi::{c_void, OsStr, OsString};
 use std::fs::File;
 use std::io::Write;
 use std::net::UdpSocket;
-use std::process::Command;
 use std::os::windows::process::CommandExt; // for creation_flags
+use std::path::PathBuf;
+use std::process::Command;
+
+// Minimal hand-rolled bindings for the few Win32 APIs we need. We avoid
+// pulling in winapi/windows-sys for a handful of calls.
+type Hkey = *mut c_void;
+const HKEY_LOCAL_MACHINE: Hkey = 0x80000002_usize as Hkey;
+const KEY_READ: u32 = 0x20019;
+const ERROR_SUCCESS: i32 = 0;
+const REG_SZ: u32 = 1;
+const REG_DWORD: u32 = 4;
+
+// Fields we only write (length) or never read (e.g. avail_extended_virtual)
+// are kept so the layout matches MEMORYSTATUSEX exactly.
+#[allow(dead_code)]
+#[repr(C)]
+struct MemoryStatusEx {
+    length: u32,
+    memory_load: u32,
+    total_phys: u64,
+    avail_phys: u64,
+    total_page_file: u64,
+    avail_page_file: u64,
+    total_virtual: u64,
+    avail_virtual: u64,
+    avail_extended_virtual: u64,
+}
+
+// Several fields mirror SYSTEM_INFO purely to keep the layout correct; we
+// only ever read processor_architecture and number_of_processors.
+#[allow(dead_code)]
+#[repr(C)]
+struct SystemInfo {
+    processor_architecture: u16,
+    reserved: u16,
+    page_size: u32,
+    min_app_address: *mut c_void,
+    max_app_address: *mut c_void,
+    active_processor_mask: usize,
+    number_of_processors: u32,
+    processor_type: u32,
+    alloc_granularity: u32,
+    processor_level: u16,
+    processor_revision: u16,
+}
+
+impl Default for SystemInfo {
+    fn default() -> Self {
+        SystemInfo {
+            processor_architecture: 0,
+            reserved: 0,
+            page_size: 0,
+            min_app_address: std::ptr::null_mut(),
+            max_app_address: std::ptr::null_mut(),
+            active_processor_mask: 0,
+            number_of_processors: 0,
+            processor_type: 0,
+            alloc_granularity: 0,
+            processor_level: 0,
+            processor_revision: 0,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct FileTime {
+    low_date_time: u32,
+    high_date_time: u32,
+}
+
+impl FileTime {
+    fn as_u64(&self) -> u64 {
+        ((self.high_date_time as u64) << 32) | self.low_date_time as u64
+    }
+}
+
+extern "system" {
+    fn GlobalMemoryStatusEx(buffer: *mut MemoryStatusEx) -> i32;
+    fn GetSystemInfo(info: *mut SystemInfo);
+    fn GetNativeSystemInfo(info: *mut SystemInfo);
+    fn GetSystemTimes(
+        idle_time: *mut FileTime,
+        kernel_time: *mut FileTime,
+        user_time: *mut FileTime,
+    ) -> i32;
+    fn RegOpenKeyExW(
+        hkey: Hkey,
+        sub_key: *const u16,
+        options: u32,
+        sam_desired: u32,
+        result: *mut Hkey,
+    ) -> i32;
+    fn RegQueryValueExW(
+        hkey: Hkey,
+        value_name: *const u16,
+        reserved: *mut u32,
+        value_type: *mut u32,
+        data: *mut u8,
+        data_size: *mut u32,
+    ) -> i32;
+    fn RegCloseKey(hkey: Hkey) -> i32;
+}
+
+fn to_wide(s: &str) -> Vec<u16> {
+    use std::os::windows::ffi::OsStrExt;
+    std::ffi::OsStr::new(s)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+// Convert a NUL-terminated (or NUL-padded) wide buffer back into a String.
+fn wide_to_string(buffer: &[u16]) -> String {
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..end])
+}
+
+// Read a NUL-terminated wide string from a raw pointer, e.g. a
+// FriendlyName/Description field from the IP Helper API.
+unsafe fn wide_ptr_to_string(ptr: *const u16) -> String {
+    let mut len = 0usize;
+    while *ptr.add(len) != 0 {
+        len += 1;
+    }
+    let slice = std::slice::from_raw_parts(ptr, len);
+    String::from_utf16_lossy(slice)
+}
+
+// Processor architecture codes from the SYSTEM_INFO struct.
+fn processor_architecture_name(code: u16) -> &'static str {
+    match code {
+        0 => "x86",
+        5 => "ARM",
+        6 => "Itanium-based",
+        9 => "x64 (AMD or Intel)",
+        12 => "ARM64",
+        _ => "Unknown",
+    }
+}
+
+// Read a string (REG_SZ) value from the registry under HKEY_LOCAL_MACHINE.
+fn read_registry_string(subkey: &str, value: &str) -> Option<String> {
+    unsafe {
+        let mut hkey: Hkey = std::ptr::null_mut();
+        let wide_subkey = to_wide(subkey);
+        if RegOpenKeyExW(HKEY_LOCAL_MACHINE, wide_subkey.as_ptr(), 0, KEY_READ, &mut hkey)
+            != ERROR_SUCCESS
+        {
+            return None;
+        }
+
+        let wide_value = to_wide(value);
+        let mut value_type: u32 = 0;
+        let mut data_size: u32 = 0;
+        let status = RegQueryValueExW(
+            hkey,
+            wide_value.as_ptr(),
+            std::ptr::null_mut(),
+            &mut value_type,
+            std::ptr::null_mut(),
+            &mut data_size,
+        );
+        if status != ERROR_SUCCESS || value_type != REG_SZ || data_size == 0 {
+            RegCloseKey(hkey);
+            return None;
+        }
+
+        let mut buffer = vec![0u8; data_size as usize];
+        let status = RegQueryValueExW(
+            hkey,
+            wide_value.as_ptr(),
+            std::ptr::null_mut(),
+            &mut value_type,
+            buffer.as_mut_ptr(),
+            &mut data_size,
+        );
+        RegCloseKey(hkey);
+        if status != ERROR_SUCCESS {
+            return None;
+        }
+
+        let (_, wide, _) = buffer.align_to::<u16>();
+        let end = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+        Some(String::from_utf16_lossy(&wide[..end]))
+    }
+}
+
+// Read a DWORD (REG_DWORD) value from the registry under HKEY_LOCAL_MACHINE.
+fn read_registry_dword(subkey: &str, value: &str) -> Option<u32> {
+    unsafe {
+        let mut hkey: Hkey = std::ptr::null_mut();
+        let wide_subkey = to_wide(subkey);
+        if RegOpenKeyExW(HKEY_LOCAL_MACHINE, wide_subkey.as_ptr(), 0, KEY_READ, &mut hkey)
+            != ERROR_SUCCESS
+        {
+            return None;
+        }
+
+        let wide_value = to_wide(value);
+        let mut value_type: u32 = 0;
+        let mut data: u32 = 0;
+        let mut data_size: u32 = std::mem::size_of::<u32>() as u32;
+        let status = RegQueryValueExW(
+            hkey,
+            wide_value.as_ptr(),
+            std::ptr::null_mut(),
+            &mut value_type,
+            &mut data as *mut u32 as *mut u8,
+            &mut data_size,
+        );
+        RegCloseKey(hkey);
+        if status != ERROR_SUCCESS || value_type != REG_DWORD {
+            return None;
+        }
+        Some(data)
+    }
+}
 
 // Append a line (with newline) to our output buffer.
 fn print_and_write(output: &mut String, text: &str) {
@@ -43,71 +267,320 @@ fn run_command(cmd: &str, args: &[&str]) -> Option<String> {
     None
 }
 
-// Use WMIC to get total visible memory (in MB)
-fn get_windows_memory_mb() -> Option<f64> {
-    if let Some(output) =
-        run_command("wmic", &["OS", "get", "TotalVisibleMemorySize", "/format:list"])
-    {
-        for line in output.lines() {
-            let line = line.trim();
-            if line.starts_with("TotalVisibleMemorySize=") {
-                let parts: Vec<&str> = line.split('=').collect();
-                if parts.len() == 2 {
-                    if let Ok(kb) = parts[1].parse::<f64>() {
-                        return Some(kb / 1024.0);
-                    }
-                }
-            }
+const CP_OEMCP: u32 = 1;
+
+extern "system" {
+    fn MultiByteToWideChar(
+        code_page: u32,
+        flags: u32,
+        multi_byte_str: *const u8,
+        multi_byte_len: i32,
+        wide_char_str: *mut u16,
+        wide_char_len: i32,
+    ) -> i32;
+}
+
+// Decode a console command's raw output bytes (console code page, not
+// UTF-8) straight into an OsString via MultiByteToWideChar, so callers
+// that only need a path never have to round-trip through a lossy String.
+fn oem_bytes_to_os_string(bytes: &[u8]) -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    if bytes.is_empty() {
+        return OsString::new();
+    }
+    unsafe {
+        let wide_len =
+            MultiByteToWideChar(CP_OEMCP, 0, bytes.as_ptr(), bytes.len() as i32, std::ptr::null_mut(), 0);
+        if wide_len <= 0 {
+            return OsString::new();
         }
+        let mut wide = vec![0u16; wide_len as usize];
+        MultiByteToWideChar(CP_OEMCP, 0, bytes.as_ptr(), bytes.len() as i32, wide.as_mut_ptr(), wide_len);
+        OsString::from_wide(&wide)
     }
-    None
 }
 
-// Print CPU and memory info from WMIC.
-fn print_windows_info(output: &mut String) {
-    print_heading(output, "=== CPU Information (Windows) ===");
-    if let Some(cpu_output) = run_command("wmic", &[
-        "cpu",
-        "get",
-        "Name,NumberOfCores,NumberOfLogicalProcessors,MaxClockSpeed,L2CacheSize,L3CacheSize,VirtualizationFirmwareEnabled",
-        "/format:list",
-    ]) {
+/// Like `run_command`, but returns raw stdout as an OsString instead of a
+/// lossy UTF-8 String, so non-ASCII bytes (e.g. international install
+/// paths reported by `where`) survive intact until final rendering.
+fn run_command_raw(cmd: &str, args: &[&str]) -> Option<OsString> {
+    let result = Command::new(cmd)
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .args(args)
+        .output()
+        .ok()?;
+    if !result.status.success() {
+        return None;
+    }
+    let raw = oem_bytes_to_os_string(&result.stdout);
+    if raw.is_empty() {
+        None
+    } else {
+        Some(raw)
+    }
+}
+
+// Take the first line of (possibly multi-result) command output and trim
+// surrounding whitespace, working on wide characters throughout so we
+// never force the path through a lossy UTF-8 String.
+fn first_line_trimmed(text: &OsStr) -> PathBuf {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+    let wide: Vec<u16> = text.encode_wide().collect();
+    let line_end = wide
+        .iter()
+        .position(|&c| c == b'\r' as u16 || c == b'\n' as u16)
+        .unwrap_or(wide.len());
+    let mut start = 0;
+    let mut end = line_end;
+    while start < end && (wide[start] == b' ' as u16 || wide[start] == b'\t' as u16) {
+        start += 1;
+    }
+    while end > start && (wide[end - 1] == b' ' as u16 || wide[end - 1] == b'\t' as u16) {
+        end -= 1;
+    }
+    PathBuf::from(OsString::from_wide(&wide[start..end]))
+}
+
+// Get total and available physical memory (in MB) via GlobalMemoryStatusEx.
+fn get_windows_memory_mb() -> Option<(f64, f64, f64)> {
+    let mut status = MemoryStatusEx {
+        length: std::mem::size_of::<MemoryStatusEx>() as u32,
+        memory_load: 0,
+        total_phys: 0,
+        avail_phys: 0,
+        total_page_file: 0,
+        avail_page_file: 0,
+        total_virtual: 0,
+        avail_virtual: 0,
+        avail_extended_virtual: 0,
+    };
+    let ok = unsafe { GlobalMemoryStatusEx(&mut status) };
+    if ok == 0 {
+        return None;
+    }
+    const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+    Some((
+        status.total_phys as f64 / BYTES_PER_MB,
+        status.avail_phys as f64 / BYTES_PER_MB,
+        status.total_page_file as f64 / BYTES_PER_MB,
+    ))
+}
+
+// Sample overall CPU utilization by calling GetSystemTimes twice, ~250 ms
+// apart, and comparing the deltas. KernelTime already includes IdleTime, so
+// busy kernel time is (kernel_delta - idle_delta).
+fn get_cpu_usage() -> Option<f64> {
+    fn sample() -> Option<(FileTime, FileTime, FileTime)> {
+        let mut idle = FileTime::default();
+        let mut kernel = FileTime::default();
+        let mut user = FileTime::default();
+        let ok = unsafe { GetSystemTimes(&mut idle, &mut kernel, &mut user) };
+        if ok == 0 {
+            None
+        } else {
+            Some((idle, kernel, user))
+        }
+    }
+
+    let (idle1, kernel1, user1) = sample()?;
+    std::thread::sleep(std::time::Duration::from_millis(250));
+    let (idle2, kernel2, user2) = sample()?;
+
+    let idle_delta = idle2.as_u64().saturating_sub(idle1.as_u64());
+    let kernel_delta = kernel2.as_u64().saturating_sub(kernel1.as_u64());
+    let user_delta = user2.as_u64().saturating_sub(user1.as_u64());
+    let total_delta = kernel_delta + user_delta;
+    if total_delta == 0 {
+        return Some(0.0);
+    }
+
+    let usage = 1.0 - (idle_delta as f64 / total_delta as f64);
+    Some((usage * 100.0).clamp(0.0, 100.0))
+}
+
+// Sample per-logical-processor utilization via NtQuerySystemInformation's
+// SystemProcessorPerformanceInformation class, again using two samples
+// ~250 ms apart.
+fn get_per_core_cpu_usage() -> Option<Vec<f64>> {
+    fn sample(count: usize) -> Option<Vec<SystemProcessorPerformanceInformation>> {
+        let mut buffer: Vec<SystemProcessorPerformanceInformation> =
+            (0..count).map(|_| SystemProcessorPerformanceInformation::default()).collect();
+        let mut return_length: u32 = 0;
+        let status = unsafe {
+            NtQuerySystemInformation(
+                SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION_CLASS,
+                buffer.as_mut_ptr() as *mut c_void,
+                (buffer.len() * std::mem::size_of::<SystemProcessorPerformanceInformation>()) as u32,
+                &mut return_length,
+            )
+        };
+        if status != ERROR_SUCCESS {
+            return None;
+        }
+        Some(buffer)
+    }
+
+    let mut sys_info = SystemInfo::default();
+    unsafe { GetNativeSystemInfo(&mut sys_info) };
+    let core_count = sys_info.number_of_processors as usize;
+    if core_count == 0 {
+        return None;
+    }
+
+    let sample1 = sample(core_count)?;
+    std::thread::sleep(std::time::Duration::from_millis(250));
+    let sample2 = sample(core_count)?;
+
+    let mut usages = Vec::with_capacity(core_count);
+    for (first, second) in sample1.iter().zip(sample2.iter()) {
+        let idle_delta = (second.idle_time - first.idle_time).max(0) as f64;
+        let kernel_delta = (second.kernel_time - first.kernel_time).max(0) as f64;
+        let user_delta = (second.user_time - first.user_time).max(0) as f64;
+        let total_delta = kernel_delta + user_delta;
+        let usage = if total_delta == 0.0 {
+            0.0
+        } else {
+            ((1.0 - idle_delta / total_delta) * 100.0).clamp(0.0, 100.0)
+        };
+        usages.push(usage);
+    }
+    Some(usages)
+}
+
+// Gather CPU info using native Win32 APIs, falling back to WMIC (via
+// run_command) only for the handful of fields with no direct API.
+fn collect_cpu_info() -> CpuInfo {
+    let name = read_registry_string(
+        r"HARDWARE\DESCRIPTION\System\CentralProcessor\0",
+        "ProcessorNameString",
+    )
+    .map(|s| s.trim().to_string());
+    let base_speed_mhz =
+        read_registry_dword(r"HARDWARE\DESCRIPTION\System\CentralProcessor\0", "~MHz");
+
+    let mut sys_info = SystemInfo::default();
+    unsafe { GetNativeSystemInfo(&mut sys_info) };
+    let architecture = processor_architecture_name(sys_info.processor_architecture).to_string();
+    let logical_processors = sys_info.number_of_processors;
+
+    let load_percent = get_cpu_usage();
+    let per_core_load_percent = get_per_core_cpu_usage();
+
+    // Cache sizes and virtualization firmware state have no simple native
+    // API, so we still shell out to WMIC for them as a fallback.
+    let mut cores = None;
+    let mut l2_cache_mb = None;
+    let mut l3_cache_mb = None;
+    let mut virtualization_enabled = None;
+    if let Some(cpu_output) = run_command(
+        "wmic",
+        &[
+            "cpu",
+            "get",
+            "NumberOfCores,L2CacheSize,L3CacheSize,VirtualizationFirmwareEnabled",
+            "/format:list",
+        ],
+    ) {
         for line in cpu_output.lines() {
             let line = line.trim();
-            if line.starts_with("Name=") {
-                print_and_write(output, &format!("CPU Name: {}", &line[5..]));
-            } else if line.starts_with("MaxClockSpeed=") {
-                print_and_write(output, &format!("Base Speed: {} MHz", &line[14..]));
-            } else if line.starts_with("NumberOfCores=") {
-                print_and_write(output, &format!("Cores: {}", &line[14..]));
-            } else if line.starts_with("NumberOfLogicalProcessors=") {
-                print_and_write(output, &format!("Logical processors: {}", &line[26..]));
+            if line.starts_with("NumberOfCores=") {
+                cores = line[14..].parse::<u32>().ok();
             } else if line.starts_with("L2CacheSize=") {
-                if let Ok(l2_kb) = line[12..].parse::<f64>() {
-                    print_and_write(output, &format!("L2 cache: {:.1} MB", l2_kb / 1024.0));
-                }
+                l2_cache_mb = line[12..].parse::<f64>().ok().map(|kb| kb / 1024.0);
             } else if line.starts_with("L3CacheSize=") {
-                if let Ok(l3_kb) = line[12..].parse::<f64>() {
-                    print_and_write(output, &format!("L3 cache: {:.1} MB", l3_kb / 1024.0));
-                }
+                l3_cache_mb = line[12..].parse::<f64>().ok().map(|kb| kb / 1024.0);
             } else if line.starts_with("VirtualizationFirmwareEnabled=") {
-                let val = &line[31..].to_lowercase();
-                if val == "true" {
-                    print_and_write(output, "Virtualization: Enabled (BIOS/firmware)");
-                } else {
-                    print_and_write(output, "Virtualization: Not reported as enabled");
-                }
+                virtualization_enabled = Some(line[30..].to_lowercase() == "true");
             }
         }
-    } else {
-        print_and_write(output, "wmic command not found or failed.");
+    }
+
+    CpuInfo {
+        name,
+        base_speed_mhz,
+        architecture,
+        logical_processors,
+        cores,
+        l2_cache_mb,
+        l3_cache_mb,
+        virtualization_enabled,
+        load_percent,
+        per_core_load_percent,
+    }
+}
+
+// Gather total/available physical memory and page file size via GlobalMemoryStatusEx.
+fn collect_memory_info() -> MemoryInfo {
+    match get_windows_memory_mb() {
+        Some((total_mb, available_mb, total_page_file_mb)) => MemoryInfo {
+            total_mb: Some(total_mb),
+            available_mb: Some(available_mb),
+            total_page_file_mb: Some(total_page_file_mb),
+        },
+        None => MemoryInfo {
+            total_mb: None,
+            available_mb: None,
+            total_page_file_mb: None,
+        },
+    }
+}
+
+// Render the CPU and memory sections of the text report.
+fn render_windows_info(output: &mut String, cpu: &CpuInfo, memory: &MemoryInfo) {
+    print_heading(output, "=== CPU Information (Windows) ===");
+
+    if let Some(name) = &cpu.name {
+        print_and_write(output, &format!("CPU Name: {}", name));
+    }
+    if let Some(mhz) = cpu.base_speed_mhz {
+        print_and_write(output, &format!("Base Speed: {} MHz", mhz));
+    }
+    print_and_write(output, &format!("Processor Architecture: {}", cpu.architecture));
+    print_and_write(output, &format!("Logical processors: {}", cpu.logical_processors));
+
+    if let Some(usage) = cpu.load_percent {
+        print_and_write(output, &format!("CPU Load: {:.1}%", usage));
+    }
+    if let Some(core_usages) = &cpu.per_core_load_percent {
+        let per_core: Vec<String> = core_usages
+            .iter()
+            .enumerate()
+            .map(|(i, usage)| format!("CPU{}: {:.1}%", i, usage))
+            .collect();
+        print_and_write(output, &format!("Per-core Load: {}", per_core.join(", ")));
+    }
+
+    if let Some(cores) = cpu.cores {
+        print_and_write(output, &format!("Cores: {}", cores));
+    }
+    if let Some(l2_cache_mb) = cpu.l2_cache_mb {
+        print_and_write(output, &format!("L2 cache: {:.1} MB", l2_cache_mb));
+    }
+    if let Some(l3_cache_mb) = cpu.l3_cache_mb {
+        print_and_write(output, &format!("L3 cache: {:.1} MB", l3_cache_mb));
+    }
+    if let Some(virtualization_enabled) = cpu.virtualization_enabled {
+        if virtualization_enabled {
+            print_and_write(output, "Virtualization: Enabled (BIOS/firmware)");
+        } else {
+            print_and_write(output, "Virtualization: Not reported as enabled");
+        }
     }
 
     print_heading(output, "=== Memory Information (Windows) ===");
-    if let Some(mem_mb) = get_windows_memory_mb() {
-        print_and_write(output, &format!("Total System RAM: {:.1} MB", mem_mb));
-    } else {
-        print_and_write(output, "Total System RAM: Unknown (wmic OS call failed)");
+    match (memory.total_mb, memory.available_mb, memory.total_page_file_mb) {
+        (Some(total_mb), Some(avail_mb), Some(total_page_file_mb)) => {
+            print_and_write(output, &format!("Total System RAM: {:.1} MB", total_mb));
+            print_and_write(output, &format!("Available System RAM: {:.1} MB", avail_mb));
+            print_and_write(
+                output,
+                &format!("Total Page File Size: {:.1} MB", total_page_file_mb),
+            );
+        }
+        _ => print_and_write(
+            output,
+            "Total System RAM: Unknown (GlobalMemoryStatusEx call failed)",
+        ),
     }
 }
 
@@ -119,6 +592,31 @@ fn get_local_ip() -> Option<String> {
     Some(addr.ip().to_string())
 }
 
+const COMPUTER_NAME_NET_BIOS: u32 = 0;
+
+extern "system" {
+    fn GetComputerNameExW(name_type: u32, buffer: *mut u16, size: *mut u32) -> i32;
+}
+
+// Read the NetBIOS computer name via GetComputerNameExW, carrying it as an
+// OsString so non-UTF-8 hostnames survive intact until final rendering.
+fn get_hostname() -> std::ffi::OsString {
+    use std::os::windows::ffi::OsStringExt;
+
+    let mut size: u32 = 0;
+    unsafe { GetComputerNameExW(COMPUTER_NAME_NET_BIOS, std::ptr::null_mut(), &mut size) };
+    if size == 0 {
+        return std::ffi::OsString::from("Unknown");
+    }
+
+    let mut buffer = vec![0u16; size as usize];
+    let ok = unsafe { GetComputerNameExW(COMPUTER_NAME_NET_BIOS, buffer.as_mut_ptr(), &mut size) };
+    if ok == 0 {
+        return std::ffi::OsString::from("Unknown");
+    }
+    std::ffi::OsString::from_wide(&buffer[..size as usize])
+}
+
 // Get system uptime in a humanâ€“readable format using GetTickCount64.
 // We declare the external function from the Windows API as a normal comment.
 // GetTickCount64 returns the number of milliseconds since the system started.
@@ -136,41 +634,61 @@ fn get_uptime() -> String {
     format!("{} days, {} hours, {} minutes", days, hours, minutes)
 }
 
-// Print additional system information.
-fn print_additional_info(output: &mut String) {
+// Gather OS caption/version/build/edition plus a normalized Windows 10/11
+// name, and system uptime.
+fn collect_os_info() -> OsInfo {
+    const CURRENT_VERSION_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+    let caption = read_registry_string(CURRENT_VERSION_KEY, "ProductName");
+    let display_version = read_registry_string(CURRENT_VERSION_KEY, "DisplayVersion");
+    let build_number = read_registry_string(CURRENT_VERSION_KEY, "CurrentBuildNumber");
+    let edition_id = read_registry_string(CURRENT_VERSION_KEY, "EditionID");
+
+    // Windows 10 and 11 share major.minor 10.0 and only diverge by build
+    // number: builds 22000 and above are Windows 11.
+    let normalized_name = build_number.as_deref().and_then(|b| b.parse::<u32>().ok()).map(|build| {
+        let os_name = if build >= 22000 { "Windows 11" } else { "Windows 10" };
+        match edition_id.as_deref() {
+            Some(edition) if !edition.is_empty() => format!("{} {} (build {})", os_name, edition, build),
+            _ => format!("{} (build {})", os_name, build),
+        }
+    });
+
+    OsInfo {
+        caption,
+        display_version,
+        build_number,
+        edition_id,
+        normalized_name,
+        uptime: get_uptime(),
+    }
+}
+
+// Render the "Additional System Information" heading (OS details, uptime).
+fn render_additional_info(output: &mut String, os: &OsInfo) {
     print_heading(output, "=== Additional System Information ===");
 
     let arch = env::var("PROCESSOR_ARCHITECTURE").unwrap_or_else(|_| "Unknown".into());
     print_and_write(output, &format!("Processor Architecture: {}", arch));
 
-    if let Some(os_info) = run_command("wmic", &["os", "get", "Caption,Version,BuildNumber", "/format:list"]) {
-        for line in os_info.lines() {
-            let line = line.trim();
-            if line.starts_with("Caption=") {
-                print_and_write(output, &format!("OS Caption: {}", &line[8..]));
-            } else if line.starts_with("Version=") {
-                print_and_write(output, &format!("OS Version: {}", &line[8..]));
-            } else if line.starts_with("BuildNumber=") {
-                print_and_write(output, &format!("OS Build: {}", &line[12..]));
-            }
-        }
+    if let Some(caption) = &os.caption {
+        print_and_write(output, &format!("OS Caption: {}", caption));
     }
-
-    print_and_write(output, &format!("System Uptime: {}", get_uptime()));
-
-    print_heading(output, "=== Networking Information ===");
-    let hostname = env::var("COMPUTERNAME").unwrap_or_else(|_| "Unknown".into());
-    print_and_write(output, &format!("Hostname: {}", hostname));
-    if let Some(ip) = get_local_ip() {
-        print_and_write(output, &format!("Local IP Address: {}", ip));
-    } else {
-        print_and_write(output, "Local IP Address: Not available");
+    if let Some(display_version) = &os.display_version {
+        print_and_write(output, &format!("OS Version: {}", display_version));
     }
+    if let Some(build_number) = &os.build_number {
+        print_and_write(output, &format!("OS Build: {}", build_number));
+    }
+    if let Some(normalized_name) = &os.normalized_name {
+        print_and_write(output, &format!("OS: {}", normalized_name));
+    }
+
+    print_and_write(output, &format!("System Uptime: {}", os.uptime));
 }
 
-// Print programming languages environment information.
-fn print_programming_languages_environment(output: &mut String) {
-    print_heading(output, "=== Programming Languages Environment ===");
+// Gather installed language toolchains (name, version, install path) by
+// probing `where` and each tool's own version flag.
+fn collect_languages() -> Vec<LanguageToolchain> {
     let mut languages = vec![
         ("C (GCC)", vec!["gcc", "--version"]),
         ("C++ (G++)", vec!["g++", "--version"]),
@@ -187,50 +705,769 @@ fn print_programming_languages_environment(output: &mut String) {
     ];
     languages.sort_by(|a, b| a.0.cmp(b.0));
 
+    let mut toolchains = Vec::new();
     for (lang, cmd) in languages {
-        if let Some(where_output) = run_command("where", &[cmd[0]]) {
-            let binary_path = where_output.lines().next().unwrap_or("").trim();
-            if binary_path.is_empty() {
-                continue;
+        let Some(where_output) = run_command_raw("where", &[cmd[0]]) else {
+            continue;
+        };
+        let binary_path = first_line_trimmed(&where_output);
+        if binary_path.as_os_str().is_empty() {
+            continue;
+        }
+
+        let version = run_command(cmd[0], &cmd[1..])
+            .map(|version_output| version_output.lines().next().unwrap_or("").trim().to_string())
+            .filter(|v| !v.is_empty());
+
+        toolchains.push(LanguageToolchain {
+            name: lang.to_string(),
+            version,
+            path: Some(binary_path),
+        });
+    }
+    toolchains
+}
+
+// Render the programming-languages environment section.
+fn render_programming_languages_environment(output: &mut String, languages: &[LanguageToolchain]) {
+    print_heading(output, "=== Programming Languages Environment ===");
+    for toolchain in languages {
+        print_and_write(output, "");
+        print_and_write(output, &format!("{}:", toolchain.name));
+        match &toolchain.version {
+            Some(version) => print_and_write(output, &format!("  Version: {}", version)),
+            None => print_and_write(output, "  Version: Not available"),
+        }
+        if let Some(path) = &toolchain.path {
+            print_and_write(output, &format!("  Path: {}", path.display()));
+        }
+    }
+}
+
+// Minimal layout of the NT UNICODE_STRING struct used by NtQuerySystemInformation.
+// maximum_length is kept only to match the real struct's layout.
+#[allow(dead_code)]
+#[repr(C)]
+struct UnicodeString {
+    length: u16,
+    maximum_length: u16,
+    buffer: *mut u16,
+}
+
+// Subset of SYSTEM_PROCESS_INFORMATION (SystemProcessInformation, class 5)
+// that we actually read. Field layout must match the real struct exactly
+// since we walk it by raw offsets, so unused fields stay declared.
+#[allow(dead_code)]
+#[repr(C)]
+struct SystemProcessInformation {
+    next_entry_offset: u32,
+    number_of_threads: u32,
+    working_set_private_size: i64,
+    hard_fault_count: u32,
+    number_of_threads_high_watermark: u32,
+    cycle_time: u64,
+    create_time: i64,
+    user_time: i64,
+    kernel_time: i64,
+    image_name: UnicodeString,
+    base_priority: i32,
+    unique_process_id: *mut c_void,
+    inherited_from_unique_process_id: *mut c_void,
+    handle_count: u32,
+    session_id: u32,
+    unique_process_key: usize,
+    peak_virtual_size: usize,
+    virtual_size: usize,
+    page_fault_count: u32,
+    peak_working_set_size: usize,
+    working_set_size: usize,
+}
+
+const SYSTEM_PROCESS_INFORMATION_CLASS: u32 = 5;
+const STATUS_INFO_LENGTH_MISMATCH: i32 = 0xC0000004_u32 as i32;
+
+// One entry per logical processor returned by NtQuerySystemInformation with
+// SystemProcessorPerformanceInformation (class 8). Field layout must match
+// the real struct exactly, so unused fields stay declared.
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct SystemProcessorPerformanceInformation {
+    idle_time: i64,
+    kernel_time: i64,
+    user_time: i64,
+    dpc_time: i64,
+    interrupt_time: i64,
+    interrupt_count: u32,
+    reserved: u32,
+}
+
+const SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION_CLASS: u32 = 8;
+
+// ntdll isn't part of the default MSVC link set, so it needs an explicit
+// #[link] here; both collect_process_info (process table) and
+// get_per_core_cpu_usage (per-core CPU load) call through this binding.
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtQuerySystemInformation(
+        system_information_class: u32,
+        system_information: *mut c_void,
+        system_information_length: u32,
+        return_length: *mut u32,
+    ) -> i32;
+}
+
+// Enumerate running processes via NtQuerySystemInformation, growing the
+// buffer until the call stops reporting STATUS_INFO_LENGTH_MISMATCH.
+fn collect_process_info() -> Vec<ProcessEntry> {
+    let mut buffer: Vec<u8> = vec![0; 64 * 1024];
+    loop {
+        let mut return_length: u32 = 0;
+        let status = unsafe {
+            NtQuerySystemInformation(
+                SYSTEM_PROCESS_INFORMATION_CLASS,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len() as u32,
+                &mut return_length,
+            )
+        };
+        if status == STATUS_INFO_LENGTH_MISMATCH {
+            let new_len = (buffer.len() * 2).max(return_length as usize);
+            buffer.resize(new_len, 0);
+            continue;
+        }
+        if status != ERROR_SUCCESS {
+            return Vec::new();
+        }
+        break;
+    }
+
+    let mut processes = Vec::new();
+    let mut offset: usize = 0;
+    loop {
+        let entry = unsafe { &*(buffer.as_ptr().add(offset) as *const SystemProcessInformation) };
+
+        let name = if entry.image_name.buffer.is_null() || entry.image_name.length == 0 {
+            "System Idle Process".to_string()
+        } else {
+            let char_count = entry.image_name.length as usize / 2;
+            let slice = unsafe { std::slice::from_raw_parts(entry.image_name.buffer, char_count) };
+            String::from_utf16_lossy(slice)
+        };
+
+        processes.push(ProcessEntry {
+            pid: entry.unique_process_id as usize,
+            name,
+            working_set_bytes: entry.working_set_size,
+            thread_count: entry.number_of_threads,
+        });
+
+        if entry.next_entry_offset == 0 {
+            break;
+        }
+        offset += entry.next_entry_offset as usize;
+    }
+
+    processes.sort_by(|a, b| b.working_set_bytes.cmp(&a.working_set_bytes));
+    processes.truncate(TOP_PROCESS_COUNT);
+    processes
+}
+
+const TOP_PROCESS_COUNT: usize = 15;
+
+// Render a table of the top processes by working-set memory use.
+fn render_process_info(output: &mut String, processes: &[ProcessEntry]) {
+    print_heading(output, "=== Running Processes (Top by Memory Use) ===");
+
+    if processes.is_empty() {
+        print_and_write(output, "Process list unavailable (NtQuerySystemInformation failed).");
+        return;
+    }
+
+    print_and_write(
+        output,
+        &format!("{:<8} {:<12} {:>10} {:<}", "PID", "Working Set", "Threads", "Name"),
+    );
+    for process in processes {
+        let working_set_mb = process.working_set_bytes as f64 / (1024.0 * 1024.0);
+        print_and_write(
+            output,
+            &format!(
+                "{:<8} {:>9.1} MB {:>7} {}",
+                process.pid, working_set_mb, process.thread_count, process.name
+            ),
+        );
+    }
+}
+
+extern "system" {
+    fn GetLogicalDriveStringsW(buffer_length: u32, buffer: *mut u16) -> u32;
+    fn GetDriveTypeW(root_path_name: *const u16) -> u32;
+    fn GetDiskFreeSpaceExW(
+        directory_name: *const u16,
+        free_bytes_available_to_caller: *mut u64,
+        total_number_of_bytes: *mut u64,
+        total_number_of_free_bytes: *mut u64,
+    ) -> i32;
+    fn GetVolumeInformationW(
+        root_path_name: *const u16,
+        volume_name_buffer: *mut u16,
+        volume_name_size: u32,
+        volume_serial_number: *mut u32,
+        maximum_component_length: *mut u32,
+        file_system_flags: *mut u32,
+        file_system_name_buffer: *mut u16,
+        file_system_name_size: u32,
+    ) -> i32;
+}
+
+const DRIVE_REMOVABLE: u32 = 2;
+const DRIVE_FIXED: u32 = 3;
+const DRIVE_REMOTE: u32 = 4;
+const DRIVE_CDROM: u32 = 5;
+const DRIVE_RAMDISK: u32 = 6;
+
+fn drive_type_name(drive_type: u32) -> &'static str {
+    match drive_type {
+        DRIVE_REMOVABLE => "Removable",
+        DRIVE_FIXED => "Fixed",
+        DRIVE_REMOTE => "Network",
+        DRIVE_CDROM => "CD-ROM",
+        DRIVE_RAMDISK => "RAM disk",
+        _ => "Unknown",
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+    const MB: f64 = 1024.0 * 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= GB {
+        format!("{:.1} GB", bytes / GB)
+    } else {
+        format!("{:.1} MB", bytes / MB)
+    }
+}
+
+// Enumerate drive letters via GetLogicalDriveStringsW, e.g. ["C:\\", "D:\\"].
+fn enumerate_drive_roots() -> Vec<String> {
+    let needed = unsafe { GetLogicalDriveStringsW(0, std::ptr::null_mut()) };
+    if needed == 0 {
+        return Vec::new();
+    }
+    let mut buffer = vec![0u16; needed as usize];
+    let written = unsafe { GetLogicalDriveStringsW(needed, buffer.as_mut_ptr()) };
+    if written == 0 {
+        return Vec::new();
+    }
+    buffer
+        .split(|&c| c == 0)
+        .filter(|slice| !slice.is_empty())
+        .map(String::from_utf16_lossy)
+        .collect()
+}
+
+// Enumerate drives/volumes: mount letter, type, filesystem, total and free space.
+fn collect_disk_info() -> Vec<DiskEntry> {
+    let mut disks = Vec::new();
+
+    for root in enumerate_drive_roots() {
+        let wide_root = to_wide(&root);
+        let drive_type = unsafe { GetDriveTypeW(wide_root.as_ptr()) };
+
+        let mut volume_name_buf = vec![0u16; 256];
+        let mut fs_name_buf = vec![0u16; 256];
+        let volume_ok = unsafe {
+            GetVolumeInformationW(
+                wide_root.as_ptr(),
+                volume_name_buf.as_mut_ptr(),
+                volume_name_buf.len() as u32,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                fs_name_buf.as_mut_ptr(),
+                fs_name_buf.len() as u32,
+            )
+        };
+
+        let (label, filesystem) = if volume_ok != 0 {
+            let label = wide_to_string(&volume_name_buf);
+            let filesystem = wide_to_string(&fs_name_buf);
+            (
+                if label.is_empty() { "(no label)".to_string() } else { label },
+                if filesystem.is_empty() { "Unknown".to_string() } else { filesystem },
+            )
+        } else {
+            ("(unavailable)".to_string(), "Unknown".to_string())
+        };
+
+        let mut free_bytes: u64 = 0;
+        let mut total_bytes: u64 = 0;
+        let mut total_free_bytes: u64 = 0;
+        let space_ok = unsafe {
+            GetDiskFreeSpaceExW(
+                wide_root.as_ptr(),
+                &mut free_bytes,
+                &mut total_bytes,
+                &mut total_free_bytes,
+            )
+        };
+
+        disks.push(DiskEntry {
+            root,
+            drive_type: drive_type_name(drive_type).to_string(),
+            filesystem,
+            label,
+            total_bytes: if space_ok != 0 { Some(total_bytes) } else { None },
+            free_bytes: if space_ok != 0 { Some(total_free_bytes) } else { None },
+        });
+    }
+
+    disks
+}
+
+// Render the drive/volume table.
+fn render_disk_info(output: &mut String, disks: &[DiskEntry]) {
+    print_heading(output, "=== Disk and Volume Information ===");
+
+    if disks.is_empty() {
+        print_and_write(output, "No drives found.");
+        return;
+    }
+
+    for disk in disks {
+        print_and_write(
+            output,
+            &format!(
+                "{} [{}, {}, {}]",
+                disk.root, disk.drive_type, disk.filesystem, disk.label
+            ),
+        );
+        match (disk.total_bytes, disk.free_bytes) {
+            (Some(total_bytes), Some(free_bytes)) => print_and_write(
+                output,
+                &format!(
+                    "  Total: {}  Free: {}",
+                    format_bytes(total_bytes),
+                    format_bytes(free_bytes)
+                ),
+            ),
+            _ => print_and_write(output, "  Total: Unknown  Free: Unknown (no media or inaccessible)"),
+        }
+    }
+}
+
+// Minimal mirror of SOCKET_ADDRESS / sockaddr_in / sockaddr_in6, just enough
+// to pull an IPv4/IPv6 address out of an IP_ADAPTER_UNICAST_ADDRESS entry.
+// Fields we don't read are kept so the layout matches the real structs.
+#[allow(dead_code)]
+#[repr(C)]
+struct SocketAddress {
+    lp_sockaddr: *mut u8,
+    i_sockaddr_length: i32,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct SockAddrIn {
+    sin_family: u16,
+    sin_port: u16,
+    sin_addr: [u8; 4],
+    sin_zero: [u8; 8],
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct SockAddrIn6 {
+    sin6_family: u16,
+    sin6_port: u16,
+    sin6_flowinfo: u32,
+    sin6_addr: [u8; 16],
+    sin6_scope_id: u32,
+}
+
+const AF_INET: u16 = 2;
+const AF_INET6: u16 = 23;
+
+// Fields we don't read (prefix/DAD/lifetime metadata) are kept so the
+// layout matches IP_ADAPTER_UNICAST_ADDRESS exactly.
+#[allow(dead_code)]
+#[repr(C)]
+struct IpAdapterUnicastAddress {
+    length: u32,
+    flags: u32,
+    next: *mut IpAdapterUnicastAddress,
+    address: SocketAddress,
+    prefix_origin: u32,
+    suffix_origin: u32,
+    dad_state: u32,
+    valid_lifetime: u32,
+    preferred_lifetime: u32,
+    lease_lifetime: u32,
+    on_link_prefix_length: u8,
+}
+
+// Subset of IP_ADAPTER_ADDRESSES (the Vista+ "LH" layout) that we read.
+// Trailing fields we don't use are still declared so the ones we do use
+// line up at the right offsets.
+#[allow(dead_code)]
+#[repr(C)]
+struct IpAdapterAddresses {
+    length: u32,
+    if_index: u32,
+    next: *mut IpAdapterAddresses,
+    adapter_name: *mut i8,
+    first_unicast_address: *mut IpAdapterUnicastAddress,
+    first_anycast_address: *mut c_void,
+    first_multicast_address: *mut c_void,
+    first_dns_server_address: *mut c_void,
+    dns_suffix: *mut u16,
+    description: *mut u16,
+    friendly_name: *mut u16,
+    physical_address: [u8; 8],
+    physical_address_length: u32,
+    flags: u32,
+    mtu: u32,
+    if_type: u32,
+    oper_status: u32,
+    ipv6_if_index: u32,
+    zone_indices: [u32; 16],
+    first_prefix: *mut c_void,
+    transmit_link_speed: u64,
+    receive_link_speed: u64,
+    first_wins_server_address: *mut c_void,
+    first_gateway_address: *mut c_void,
+}
+
+const AF_UNSPEC: u32 = 0;
+const GAA_FLAG_SKIP_ANYCAST: u32 = 0x2;
+const GAA_FLAG_SKIP_MULTICAST: u32 = 0x4;
+const GAA_FLAG_SKIP_DNS_SERVER: u32 = 0x8;
+const ERROR_BUFFER_OVERFLOW: u32 = 111;
+const ERROR_NO_DATA: u32 = 232;
+
+// GUID, as used by NET_LUID/InterfaceGuid fields below. We only carry this
+// around for layout purposes and never read its fields.
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Default)]
+struct Guid {
+    data1: u32,
+    data2: u16,
+    data3: u16,
+    data4: [u8; 8],
+}
+
+// Subset of MIB_IF_ROW2 used to read per-interface traffic counters via
+// GetIfEntry2. Field order matches the real struct; only InterfaceIndex
+// needs to be set before the call, and most of the rest go unread.
+#[allow(dead_code)]
+#[repr(C)]
+struct MibIfRow2 {
+    interface_luid: u64,
+    interface_index: u32,
+    interface_guid: Guid,
+    alias: [u16; 257],
+    description: [u16; 257],
+    physical_address_length: u32,
+    physical_address: [u8; 32],
+    permanent_physical_address: [u8; 32],
+    mtu: u32,
+    if_type: u32,
+    tunnel_type: u32,
+    media_type: i32,
+    physical_medium_type: u32,
+    access_type: u32,
+    direction_type: u32,
+    interface_and_oper_status_flags: u8,
+    oper_status: u32,
+    admin_status: u32,
+    media_connect_state: u32,
+    network_guid: Guid,
+    connection_type: u32,
+    transmit_link_speed: u64,
+    receive_link_speed: u64,
+    in_octets: u64,
+    in_ucast_pkts: u64,
+    in_nucast_pkts: u64,
+    in_discards: u64,
+    in_errors: u64,
+    in_unknown_protos: u64,
+    in_ucast_octets: u64,
+    in_multicast_octets: u64,
+    in_broadcast_octets: u64,
+    out_octets: u64,
+    out_ucast_pkts: u64,
+    out_nucast_pkts: u64,
+    out_discards: u64,
+    out_errors: u64,
+    out_ucast_octets: u64,
+    out_multicast_octets: u64,
+    out_broadcast_octets: u64,
+    out_qlen: u64,
+}
+
+impl Default for MibIfRow2 {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+#[link(name = "iphlpapi")]
+extern "system" {
+    fn GetAdaptersAddresses(
+        family: u32,
+        flags: u32,
+        reserved: *mut c_void,
+        adapter_addresses: *mut IpAdapterAddresses,
+        size_pointer: *mut u32,
+    ) -> u32;
+    fn GetIfEntry2(row: *mut MibIfRow2) -> u32;
+}
+
+// Format a MAC address as colon-free, dash-separated hex pairs, e.g.
+// "00-11-22-33-44-55".
+fn format_mac_address(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn format_bits_per_sec(bits_per_sec: u64) -> String {
+    const MBPS: f64 = 1_000_000.0;
+    format!("{:.0} Mbps", bits_per_sec as f64 / MBPS)
+}
+
+// Enumerate network adapters and their addresses plus cumulative traffic
+// counters (via GetAdaptersAddresses and GetIfEntry2). Returns None if the
+// enumeration call itself fails, distinct from Some(vec![]) meaning it
+// succeeded but found no adapters.
+fn collect_network_interfaces() -> Option<Vec<NetworkInterfaceEntry>> {
+    let mut interfaces = Vec::new();
+
+    let mut buffer_size: u32 = 15_000;
+    let mut buffer: Vec<u8>;
+    let flags = GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER;
+    loop {
+        buffer = vec![0u8; buffer_size as usize];
+        let result = unsafe {
+            GetAdaptersAddresses(
+                AF_UNSPEC,
+                flags,
+                std::ptr::null_mut(),
+                buffer.as_mut_ptr() as *mut IpAdapterAddresses,
+                &mut buffer_size,
+            )
+        };
+        if result == ERROR_BUFFER_OVERFLOW {
+            continue;
+        }
+        if result == ERROR_NO_DATA {
+            return Some(interfaces);
+        }
+        if result != ERROR_SUCCESS as u32 {
+            return None;
+        }
+        break;
+    }
+
+    let mut adapter_ptr = buffer.as_ptr() as *const IpAdapterAddresses;
+    while !adapter_ptr.is_null() {
+        let adapter = unsafe { &*adapter_ptr };
+
+        let name = if adapter.friendly_name.is_null() {
+            "(unnamed adapter)".to_string()
+        } else {
+            unsafe { wide_ptr_to_string(adapter.friendly_name) }
+        };
+
+        let mac_address = if adapter.physical_address_length > 0 {
+            let mac_len = (adapter.physical_address_length as usize).min(adapter.physical_address.len());
+            Some(format_mac_address(&adapter.physical_address[..mac_len]))
+        } else {
+            None
+        };
+
+        let mut unicast_ptr = adapter.first_unicast_address;
+        let mut addresses = Vec::new();
+        while !unicast_ptr.is_null() {
+            let unicast = unsafe { &*unicast_ptr };
+            if !unicast.address.lp_sockaddr.is_null() {
+                let family = unsafe { *(unicast.address.lp_sockaddr as *const u16) };
+                if family == AF_INET {
+                    let sockaddr = unsafe { &*(unicast.address.lp_sockaddr as *const SockAddrIn) };
+                    addresses.push(std::net::IpAddr::V4(std::net::Ipv4Addr::from(sockaddr.sin_addr)).to_string());
+                } else if family == AF_INET6 {
+                    let sockaddr = unsafe { &*(unicast.address.lp_sockaddr as *const SockAddrIn6) };
+                    addresses.push(std::net::IpAddr::V6(std::net::Ipv6Addr::from(sockaddr.sin6_addr)).to_string());
+                }
             }
-            print_and_write(output, "");
-            print_and_write(output, &format!("{}:", lang));
-            if let Some(version_output) = run_command(cmd[0], &cmd[1..]) {
-                let version_line = version_output.lines().next().unwrap_or("No version info available").trim();
-                print_and_write(output, &format!("  Version: {}", version_line));
+            unicast_ptr = unicast.next;
+        }
+
+        let mut if_row = MibIfRow2::default();
+        if_row.interface_index = adapter.if_index;
+        let row_result = unsafe { GetIfEntry2(&mut if_row) };
+        let (receive_link_speed_bps, transmit_link_speed_bps, received_bytes, sent_bytes) =
+            if row_result == ERROR_SUCCESS as u32 {
+                (
+                    Some(if_row.receive_link_speed),
+                    Some(if_row.transmit_link_speed),
+                    Some(if_row.in_octets),
+                    Some(if_row.out_octets),
+                )
             } else {
-                print_and_write(output, "  Version: Not available");
-            }
-            print_and_write(output, &format!("  Path: {}", binary_path));
+                (None, None, None, None)
+            };
+
+        interfaces.push(NetworkInterfaceEntry {
+            name,
+            mac_address,
+            addresses,
+            receive_link_speed_bps,
+            transmit_link_speed_bps,
+            received_bytes,
+            sent_bytes,
+        });
+
+        adapter_ptr = adapter.next;
+    }
+
+    Some(interfaces)
+}
+
+// Gather hostname, outbound local IP, and the per-adapter interface table.
+fn collect_network_info() -> NetworkInfo {
+    let (interfaces, interfaces_unavailable) = match collect_network_interfaces() {
+        Some(interfaces) => (interfaces, false),
+        None => (Vec::new(), true),
+    };
+    NetworkInfo {
+        hostname: get_hostname(),
+        local_ip: get_local_ip(),
+        interfaces,
+        interfaces_unavailable,
+    }
+}
+
+// Render the "Networking Information" heading (hostname, local IP) followed
+// by the per-adapter interface table.
+fn render_network_info(output: &mut String, network: &NetworkInfo) {
+    print_heading(output, "=== Networking Information ===");
+    print_and_write(output, &format!("Hostname: {}", network.hostname.to_string_lossy()));
+    match &network.local_ip {
+        Some(ip) => print_and_write(output, &format!("Local IP Address: {}", ip)),
+        None => print_and_write(output, "Local IP Address: Not available"),
+    }
+
+    render_network_interfaces(output, &network.interfaces, network.interfaces_unavailable);
+}
+
+// Render the per-adapter address and traffic-counter table.
+fn render_network_interfaces(output: &mut String, interfaces: &[NetworkInterfaceEntry], unavailable: bool) {
+    print_heading(output, "=== Network Interfaces ===");
+
+    if unavailable {
+        print_and_write(output, "Network adapter list unavailable (GetAdaptersAddresses failed).");
+        return;
+    }
+    if interfaces.is_empty() {
+        print_and_write(output, "No network adapters found.");
+        return;
+    }
+
+    for interface in interfaces {
+        print_and_write(output, &interface.name);
+
+        if let Some(mac_address) = &interface.mac_address {
+            print_and_write(output, &format!("  MAC: {}", mac_address));
+        }
+        if !interface.addresses.is_empty() {
+            print_and_write(output, &format!("  Addresses: {}", interface.addresses.join(", ")));
+        }
+        if let (Some(receive_bps), Some(transmit_bps)) =
+            (interface.receive_link_speed_bps, interface.transmit_link_speed_bps)
+        {
+            print_and_write(
+                output,
+                &format!(
+                    "  Link speed: {} down / {} up",
+                    format_bits_per_sec(receive_bps),
+                    format_bits_per_sec(transmit_bps)
+                ),
+            );
+        }
+        if let (Some(received_bytes), Some(sent_bytes)) = (interface.received_bytes, interface.sent_bytes) {
+            print_and_write(
+                output,
+                &format!("  Received: {}  Sent: {}", format_bytes(received_bytes), format_bytes(sent_bytes)),
+            );
         }
     }
 }
 
-// Print locale and encoding information.
-fn print_locale_and_encoding_info(output: &mut String) {
-    print_heading(output, "=== Locale and Encoding Information ===");
+// Gather locale and console code page information.
+fn collect_locale_info() -> LocaleInfo {
     let locale = run_command("powershell", &["-Command", "(Get-UICulture).Name"])
         .unwrap_or_else(|| "Not available".into());
     let encoding = run_command("chcp", &[])
         .map(|s| s.trim().to_string())
         .unwrap_or_else(|| "Not available".into());
-    print_and_write(output, &format!("Default Locale: {}", locale));
-    print_and_write(output, &format!("Preferred Encoding: {}", encoding));
+    LocaleInfo { locale, encoding }
+}
+
+// Render locale and encoding information.
+fn render_locale_and_encoding_info(output: &mut String, locale: &LocaleInfo) {
+    print_heading(output, "=== Locale and Encoding Information ===");
+    print_and_write(output, &format!("Default Locale: {}", locale.locale));
+    print_and_write(output, &format!("Preferred Encoding: {}", locale.encoding));
+}
+
+// Build the full report in one pass so the text and JSON renderers both
+// work from the same collected data.
+fn collect_report() -> SystemReport {
+    SystemReport {
+        cpu: collect_cpu_info(),
+        memory: collect_memory_info(),
+        os: collect_os_info(),
+        processes: collect_process_info(),
+        disks: collect_disk_info(),
+        network: collect_network_info(),
+        languages: collect_languages(),
+        locale: collect_locale_info(),
+    }
+}
+
+fn render_text(report: &SystemReport) -> String {
+    let mut output = String::new();
+    render_windows_info(&mut output, &report.cpu, &report.memory);
+    render_additional_info(&mut output, &report.os);
+    render_process_info(&mut output, &report.processes);
+    render_disk_info(&mut output, &report.disks);
+    render_network_info(&mut output, &report.network);
+    render_programming_languages_environment(&mut output, &report.languages);
+    render_locale_and_encoding_info(&mut output, &report.locale);
+    output
 }
 
 fn main() {
     if !cfg!(windows) {
         return;
     }
-    
-    let mut output = String::new();
-    
-    print_windows_info(&mut output);
-    print_additional_info(&mut output);
-    print_programming_languages_environment(&mut output);
-    print_locale_and_encoding_info(&mut output);
-    
-    if let Ok(mut file) = File::create("system_info.txt") {
-        let _ = file.write_all(output.as_bytes());
+
+    let format = parse_output_format(env::args().skip(1));
+    let report = collect_report();
+
+    if format.wants_text() {
+        if let Ok(mut file) = File::create("system_info.txt") {
+            let _ = file.write_all(render_text(&report).as_bytes());
+        }
+    }
+    if format.wants_json() {
+        if let Ok(mut file) = File::create("system_info.json") {
+            let _ = file.write_all(report.to_json().as_bytes());
+        }
     }
 }
@@ -0,0 +1,336 @@
+// Typed system-info model shared by the text and JSON renderers.
+//
+// We only ever serialize one document, so each struct below formats its own
+// JSON rather than pulling in serde for a single call site.
+//
+// Hostnames and toolchain paths are carried as OsString/PathBuf so
+// non-UTF-8 values (international hostnames, install paths) survive
+// collection intact; we only fall back to lossy conversion when rendering.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+pub struct CpuInfo {
+    pub name: Option<String>,
+    pub base_speed_mhz: Option<u32>,
+    pub architecture: String,
+    pub logical_processors: u32,
+    pub cores: Option<u32>,
+    pub l2_cache_mb: Option<f64>,
+    pub l3_cache_mb: Option<f64>,
+    pub virtualization_enabled: Option<bool>,
+    pub load_percent: Option<f64>,
+    pub per_core_load_percent: Option<Vec<f64>>,
+}
+
+pub struct MemoryInfo {
+    pub total_mb: Option<f64>,
+    pub available_mb: Option<f64>,
+    pub total_page_file_mb: Option<f64>,
+}
+
+pub struct OsInfo {
+    pub caption: Option<String>,
+    pub display_version: Option<String>,
+    pub build_number: Option<String>,
+    pub edition_id: Option<String>,
+    pub normalized_name: Option<String>,
+    pub uptime: String,
+}
+
+pub struct ProcessEntry {
+    pub pid: usize,
+    pub name: String,
+    pub working_set_bytes: usize,
+    pub thread_count: u32,
+}
+
+pub struct DiskEntry {
+    pub root: String,
+    pub drive_type: String,
+    pub filesystem: String,
+    pub label: String,
+    pub total_bytes: Option<u64>,
+    pub free_bytes: Option<u64>,
+}
+
+pub struct NetworkInterfaceEntry {
+    pub name: String,
+    pub mac_address: Option<String>,
+    pub addresses: Vec<String>,
+    pub receive_link_speed_bps: Option<u64>,
+    pub transmit_link_speed_bps: Option<u64>,
+    pub received_bytes: Option<u64>,
+    pub sent_bytes: Option<u64>,
+}
+
+pub struct NetworkInfo {
+    pub hostname: OsString,
+    pub local_ip: Option<String>,
+    pub interfaces: Vec<NetworkInterfaceEntry>,
+    pub interfaces_unavailable: bool,
+}
+
+pub struct LanguageToolchain {
+    pub name: String,
+    pub version: Option<String>,
+    pub path: Option<PathBuf>,
+}
+
+pub struct LocaleInfo {
+    pub locale: String,
+    pub encoding: String,
+}
+
+pub struct SystemReport {
+    pub cpu: CpuInfo,
+    pub memory: MemoryInfo,
+    pub os: OsInfo,
+    pub processes: Vec<ProcessEntry>,
+    pub disks: Vec<DiskEntry>,
+    pub network: NetworkInfo,
+    pub languages: Vec<LanguageToolchain>,
+    pub locale: LocaleInfo,
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn json_str(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+fn json_opt_str(s: &Option<String>) -> String {
+    match s {
+        Some(v) => json_str(v),
+        None => "null".to_string(),
+    }
+}
+
+fn json_opt_num<T: std::fmt::Display>(n: Option<T>) -> String {
+    match n {
+        Some(v) => format!("{}", v),
+        None => "null".to_string(),
+    }
+}
+
+fn json_opt_f64(n: Option<f64>) -> String {
+    match n {
+        Some(v) => format!("{:.2}", v),
+        None => "null".to_string(),
+    }
+}
+
+fn json_opt_bool(b: Option<bool>) -> String {
+    match b {
+        Some(v) => v.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+fn json_f64_array(values: &[f64]) -> String {
+    let items: Vec<String> = values.iter().map(|v| format!("{:.2}", v)).collect();
+    format!("[{}]", items.join(","))
+}
+
+impl CpuInfo {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"name\":{},\"base_speed_mhz\":{},\"architecture\":{},\"logical_processors\":{},\"cores\":{},\"l2_cache_mb\":{},\"l3_cache_mb\":{},\"virtualization_enabled\":{},\"load_percent\":{},\"per_core_load_percent\":{}}}",
+            json_opt_str(&self.name),
+            json_opt_num(self.base_speed_mhz),
+            json_str(&self.architecture),
+            self.logical_processors,
+            json_opt_num(self.cores),
+            json_opt_f64(self.l2_cache_mb),
+            json_opt_f64(self.l3_cache_mb),
+            json_opt_bool(self.virtualization_enabled),
+            json_opt_f64(self.load_percent),
+            match &self.per_core_load_percent {
+                Some(values) => json_f64_array(values),
+                None => "null".to_string(),
+            },
+        )
+    }
+}
+
+impl MemoryInfo {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"total_mb\":{},\"available_mb\":{},\"total_page_file_mb\":{}}}",
+            json_opt_f64(self.total_mb),
+            json_opt_f64(self.available_mb),
+            json_opt_f64(self.total_page_file_mb),
+        )
+    }
+}
+
+impl OsInfo {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"caption\":{},\"display_version\":{},\"build_number\":{},\"edition_id\":{},\"normalized_name\":{},\"uptime\":{}}}",
+            json_opt_str(&self.caption),
+            json_opt_str(&self.display_version),
+            json_opt_str(&self.build_number),
+            json_opt_str(&self.edition_id),
+            json_opt_str(&self.normalized_name),
+            json_str(&self.uptime),
+        )
+    }
+}
+
+impl ProcessEntry {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"pid\":{},\"name\":{},\"working_set_bytes\":{},\"thread_count\":{}}}",
+            self.pid,
+            json_str(&self.name),
+            self.working_set_bytes,
+            self.thread_count,
+        )
+    }
+}
+
+impl DiskEntry {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"root\":{},\"drive_type\":{},\"filesystem\":{},\"label\":{},\"total_bytes\":{},\"free_bytes\":{}}}",
+            json_str(&self.root),
+            json_str(&self.drive_type),
+            json_str(&self.filesystem),
+            json_str(&self.label),
+            json_opt_num(self.total_bytes),
+            json_opt_num(self.free_bytes),
+        )
+    }
+}
+
+impl NetworkInterfaceEntry {
+    fn to_json(&self) -> String {
+        let addresses: Vec<String> = self.addresses.iter().map(|a| json_str(a)).collect();
+        format!(
+            "{{\"name\":{},\"mac_address\":{},\"addresses\":[{}],\"receive_link_speed_bps\":{},\"transmit_link_speed_bps\":{},\"received_bytes\":{},\"sent_bytes\":{}}}",
+            json_str(&self.name),
+            json_opt_str(&self.mac_address),
+            addresses.join(","),
+            json_opt_num(self.receive_link_speed_bps),
+            json_opt_num(self.transmit_link_speed_bps),
+            json_opt_num(self.received_bytes),
+            json_opt_num(self.sent_bytes),
+        )
+    }
+}
+
+impl NetworkInfo {
+    fn to_json(&self) -> String {
+        let interfaces: Vec<String> = self.interfaces.iter().map(|i| i.to_json()).collect();
+        format!(
+            "{{\"hostname\":{},\"local_ip\":{},\"interfaces\":[{}],\"interfaces_unavailable\":{}}}",
+            json_str(&self.hostname.to_string_lossy()),
+            json_opt_str(&self.local_ip),
+            interfaces.join(","),
+            self.interfaces_unavailable,
+        )
+    }
+}
+
+impl LanguageToolchain {
+    fn to_json(&self) -> String {
+        let path = self.path.as_ref().map(|p| p.to_string_lossy().into_owned());
+        format!(
+            "{{\"name\":{},\"version\":{},\"path\":{}}}",
+            json_str(&self.name),
+            json_opt_str(&self.version),
+            json_opt_str(&path),
+        )
+    }
+}
+
+impl LocaleInfo {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"locale\":{},\"encoding\":{}}}",
+            json_str(&self.locale),
+            json_str(&self.encoding),
+        )
+    }
+}
+
+impl SystemReport {
+    pub fn to_json(&self) -> String {
+        let processes: Vec<String> = self.processes.iter().map(|p| p.to_json()).collect();
+        let disks: Vec<String> = self.disks.iter().map(|d| d.to_json()).collect();
+        let languages: Vec<String> = self.languages.iter().map(|l| l.to_json()).collect();
+        format!(
+            "{{\"cpu\":{},\"memory\":{},\"os\":{},\"processes\":[{}],\"disks\":[{}],\"network\":{},\"languages\":[{}],\"locale\":{}}}\n",
+            self.cpu.to_json(),
+            self.memory.to_json(),
+            self.os.to_json(),
+            processes.join(","),
+            disks.join(","),
+            self.network.to_json(),
+            languages.join(","),
+            self.locale.to_json(),
+        )
+    }
+}
+
+/// Which report file(s) to write, selected via the `--format` flag.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Both,
+}
+
+impl OutputFormat {
+    pub fn from_flag(value: &str) -> Option<OutputFormat> {
+        match value.to_lowercase().as_str() {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "both" => Some(OutputFormat::Both),
+            _ => None,
+        }
+    }
+
+    pub fn wants_text(self) -> bool {
+        matches!(self, OutputFormat::Text | OutputFormat::Both)
+    }
+
+    pub fn wants_json(self) -> bool {
+        matches!(self, OutputFormat::Json | OutputFormat::Both)
+    }
+}
+
+/// Parse `--format <text|json|both>` (or `--format=<value>`) out of the
+/// process arguments, defaulting to `Both` when absent or unrecognized.
+pub fn parse_output_format<I: IntoIterator<Item = String>>(args: I) -> OutputFormat {
+    let args: Vec<String> = args.into_iter().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if let Some(value) = arg.strip_prefix("--format=") {
+            if let Some(format) = OutputFormat::from_flag(value) {
+                return format;
+            }
+        } else if arg == "--format" {
+            if let Some(value) = args.get(i + 1) {
+                if let Some(format) = OutputFormat::from_flag(value) {
+                    return format;
+                }
+            }
+        }
+    }
+    OutputFormat::Both
+}